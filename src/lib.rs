@@ -11,30 +11,168 @@
 //! # }
 //! ```
 
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+
 #[derive(Copy, Clone)]
 enum CommaSeparatedIteratorState {
     /// Non quoted part
     Default,
-    /// Inside a quote
-    Quoted(Quote),
-    /// After escape character inside quote
-    QuotedEscape(Quote),
+    /// Inside a quote, carrying the character that opened it
+    Quoted(char),
+    /// After escape character inside quote, carrying the quote it's nested in
+    QuotedEscape(char),
 }
 
-#[derive(Copy, Clone)]
-enum Quote {
-    Single,
-    Double,
+/// The delimiter, quote characters and escape character used by a [`CommaSeparatedIterator`]
+#[derive(Clone)]
+struct CommaSeparatedConfig {
+    delimiter: char,
+    quote_chars: Vec<char>,
+    escape: Option<char>,
+}
+
+impl Default for CommaSeparatedConfig {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote_chars: vec!['\'', '"'],
+            escape: Some('\\'),
+        }
+    }
+}
+
+/// Builder for a [`CommaSeparatedIterator`] with a configurable delimiter, quote characters and escape character
+///
+/// # Example
+///
+/// ```rust
+/// # use comma_separated::CommaSeparatedIterator;
+/// # fn main() {
+/// let input = "foo;'bar baz';qux";
+/// let iterator = CommaSeparatedIterator::builder()
+///     .delimiter(';')
+///     .quote_chars(&['\''])
+///     .build(input);
+/// assert_eq!(vec!["foo", "'bar baz'", "qux"], iterator.collect::<Vec<_>>());
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct CommaSeparatedIteratorBuilder {
+    config: CommaSeparatedConfig,
+}
+
+impl CommaSeparatedIteratorBuilder {
+    /// Set the character that separates fields, default `,`
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.config.delimiter = delimiter;
+        self
+    }
+
+    /// Set the characters that open and close a quoted section, default `'` and `"`
+    pub fn quote_chars(mut self, quote_chars: &[char]) -> Self {
+        self.config.quote_chars = quote_chars.to_vec();
+        self
+    }
+
+    /// Set the character that escapes the next character inside a quoted section, default `\`
+    pub fn escape(mut self, escape: char) -> Self {
+        self.config.escape = Some(escape);
+        self
+    }
+
+    /// Disable escape handling inside quoted sections
+    pub fn no_escape(mut self) -> Self {
+        self.config.escape = None;
+        self
+    }
+
+    /// Build the iterator for the given input
+    pub fn build(self, text: &str) -> CommaSeparatedIterator<'_> {
+        CommaSeparatedIterator {
+            remaining: text,
+            config: self.config,
+        }
+    }
+
+    /// Build a fallible iterator for the given input that reports unterminated quotes and dangling escapes
+    /// instead of guessing where they end
+    pub fn try_build(self, text: &str) -> CommaSeparatedResult<'_> {
+        CommaSeparatedResult {
+            remaining: text,
+            config: self.config,
+            offset: 0,
+        }
+    }
+
+    /// Build an iterator for the given input that yields the byte-range span of each field within `text`
+    /// alongside its content
+    pub fn build_spans(self, text: &str) -> CommaSeparatedSpans<'_> {
+        CommaSeparatedSpans {
+            remaining: text,
+            config: self.config,
+            start: 0,
+        }
+    }
+
+    /// Build an iterator for the given input that keeps the trailing delimiter as part of each field, so
+    /// that concatenating every yielded field reproduces `text` exactly
+    pub fn build_inclusive(self, text: &str) -> CommaSeparatedInclusive<'_> {
+        CommaSeparatedInclusive {
+            remaining: text,
+            config: self.config,
+        }
+    }
 }
 
 pub struct CommaSeparatedIterator<'a> {
     remaining: &'a str,
+    config: CommaSeparatedConfig,
 }
 
 impl<'a> CommaSeparatedIterator<'a> {
     /// Create a new iterator, splitting the input into comma-seperated parts with handling of quoted segments
     pub fn new(text: &'a str) -> Self {
-        Self { remaining: text }
+        Self::builder().build(text)
+    }
+
+    /// Create a builder to customize the delimiter, quote characters or escape character
+    pub fn builder() -> CommaSeparatedIteratorBuilder {
+        CommaSeparatedIteratorBuilder::default()
+    }
+
+    /// Turn this iterator into one that yields the decoded content of each field instead of the raw slice
+    ///
+    /// Quote marks are stripped and escaped characters inside quotes are resolved, so `"bar"` becomes `bar`
+    /// and `"foo\"bar"` becomes `foo"bar`. Fields that don't need any decoding are returned without allocating.
+    pub fn values(self) -> CommaSeparatedValues<'a> {
+        CommaSeparatedValues {
+            remaining: self.remaining,
+            config: self.config,
+        }
+    }
+
+    /// Turn this iterator into a fallible one that reports unterminated quotes and dangling escapes instead
+    /// of guessing where they end
+    pub fn try_next(self) -> CommaSeparatedResult<'a> {
+        CommaSeparatedResult {
+            remaining: self.remaining,
+            config: self.config,
+            offset: 0,
+        }
+    }
+
+    /// Split `text` into comma-seperated parts like [`new`](Self::new), yielding the byte-range span of each
+    /// field within `text` alongside its content
+    pub fn spans(text: &'a str) -> CommaSeparatedSpans<'a> {
+        CommaSeparatedIteratorBuilder::default().build_spans(text)
+    }
+
+    /// Split `text` into comma-seperated parts like [`new`](Self::new), but keep the trailing delimiter as
+    /// part of each field so that concatenating every yielded field reproduces `text` exactly
+    pub fn split_inclusive(text: &'a str) -> CommaSeparatedInclusive<'a> {
+        CommaSeparatedIteratorBuilder::default().build_inclusive(text)
     }
 }
 
@@ -50,41 +188,130 @@ impl<'a> Iterator for CommaSeparatedIterator<'a> {
         let char_indices = self.remaining.char_indices();
 
         for (i, c) in char_indices {
-            state = match (state, c) {
-                (CommaSeparatedIteratorState::Default, '"') => {
-                    CommaSeparatedIteratorState::Quoted(Quote::Double)
+            state = match state {
+                CommaSeparatedIteratorState::Default if self.config.quote_chars.contains(&c) => {
+                    CommaSeparatedIteratorState::Quoted(c)
                 }
-                (CommaSeparatedIteratorState::Default, '\'') => {
-                    CommaSeparatedIteratorState::Quoted(Quote::Single)
+                CommaSeparatedIteratorState::Default if c == self.config.delimiter => {
+                    let result = &self.remaining[0..i];
+                    self.remaining = &self.remaining[i + c.len_utf8()..];
+                    return Some(result);
                 }
-                (CommaSeparatedIteratorState::Quoted(Quote::Double), '"')
-                | (CommaSeparatedIteratorState::Quoted(Quote::Single), '\'') => {
+                CommaSeparatedIteratorState::Default => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Quoted(quote) if c == quote => {
                     CommaSeparatedIteratorState::Default
                 }
-                (CommaSeparatedIteratorState::Quoted(quote), '\\') => {
+                CommaSeparatedIteratorState::Quoted(quote) if Some(c) == self.config.escape => {
                     CommaSeparatedIteratorState::QuotedEscape(quote)
                 }
-                (CommaSeparatedIteratorState::Quoted(quote), _) => {
+                CommaSeparatedIteratorState::Quoted(quote) => {
                     CommaSeparatedIteratorState::Quoted(quote)
                 }
-                (CommaSeparatedIteratorState::QuotedEscape(quote), _) => {
+                CommaSeparatedIteratorState::QuotedEscape(quote) => {
                     CommaSeparatedIteratorState::Quoted(quote)
                 }
-                (CommaSeparatedIteratorState::Default, ',') => {
-                    let result = &self.remaining[0..i];
-                    self.remaining = &self.remaining[i + 1..];
+            };
+        }
+        let result = self.remaining;
+        self.remaining = "";
+        Some(result)
+    }
+}
+
+impl<'a> DoubleEndedIterator for CommaSeparatedIterator<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let mut state = CommaSeparatedIteratorState::Default;
+        let mut char_indices = self.remaining.char_indices().rev().peekable();
+
+        while let Some((i, c)) = char_indices.next() {
+            state = match state {
+                CommaSeparatedIteratorState::Default if self.config.quote_chars.contains(&c) => {
+                    CommaSeparatedIteratorState::Quoted(c)
+                }
+                CommaSeparatedIteratorState::Default if c == self.config.delimiter => {
+                    let result = &self.remaining[i + c.len_utf8()..];
+                    self.remaining = &self.remaining[0..i];
                     return Some(result);
                 }
-                (CommaSeparatedIteratorState::Default, _) => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Default => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Quoted(quote) if c == quote => {
+                    if char_indices.peek().map(|(_, c)| *c) == self.config.escape {
+                        CommaSeparatedIteratorState::Quoted(quote)
+                    } else {
+                        CommaSeparatedIteratorState::Default
+                    }
+                }
+                CommaSeparatedIteratorState::Quoted(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+                CommaSeparatedIteratorState::QuotedEscape(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
             };
         }
+
         let result = self.remaining;
         self.remaining = "";
         Some(result)
     }
 }
 
-impl<'a> DoubleEndedIterator for CommaSeparatedIterator<'a> {
+/// Iterator over a comma-seperated string, yielding the decoded content of each field
+///
+/// Created by [`CommaSeparatedIterator::values`].
+pub struct CommaSeparatedValues<'a> {
+    remaining: &'a str,
+    config: CommaSeparatedConfig,
+}
+
+impl<'a> Iterator for CommaSeparatedValues<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let mut state = CommaSeparatedIteratorState::Default;
+        let char_indices = self.remaining.char_indices();
+
+        for (i, c) in char_indices {
+            state = match state {
+                CommaSeparatedIteratorState::Default if self.config.quote_chars.contains(&c) => {
+                    CommaSeparatedIteratorState::Quoted(c)
+                }
+                CommaSeparatedIteratorState::Default if c == self.config.delimiter => {
+                    let field = &self.remaining[0..i];
+                    self.remaining = &self.remaining[i + c.len_utf8()..];
+                    return Some(decode(field, &self.config));
+                }
+                CommaSeparatedIteratorState::Default => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Quoted(quote) if c == quote => {
+                    CommaSeparatedIteratorState::Default
+                }
+                CommaSeparatedIteratorState::Quoted(quote) if Some(c) == self.config.escape => {
+                    CommaSeparatedIteratorState::QuotedEscape(quote)
+                }
+                CommaSeparatedIteratorState::Quoted(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+                CommaSeparatedIteratorState::QuotedEscape(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+            };
+        }
+
+        let field = self.remaining;
+        self.remaining = "";
+        Some(decode(field, &self.config))
+    }
+}
+
+impl<'a> DoubleEndedIterator for CommaSeparatedValues<'a> {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.remaining.is_empty() {
             return None;
@@ -94,33 +321,338 @@ impl<'a> DoubleEndedIterator for CommaSeparatedIterator<'a> {
         let mut char_indices = self.remaining.char_indices().rev().peekable();
 
         while let Some((i, c)) = char_indices.next() {
-            state = match (state, c) {
-                (CommaSeparatedIteratorState::Default, '"') => {
-                    CommaSeparatedIteratorState::Quoted(Quote::Double)
+            state = match state {
+                CommaSeparatedIteratorState::Default if self.config.quote_chars.contains(&c) => {
+                    CommaSeparatedIteratorState::Quoted(c)
                 }
-                (CommaSeparatedIteratorState::Default, '\'') => {
-                    CommaSeparatedIteratorState::Quoted(Quote::Single)
+                CommaSeparatedIteratorState::Default if c == self.config.delimiter => {
+                    let field = &self.remaining[i + c.len_utf8()..];
+                    self.remaining = &self.remaining[0..i];
+                    return Some(decode(field, &self.config));
                 }
-                (CommaSeparatedIteratorState::Quoted(quote @ Quote::Double), '"')
-                | (CommaSeparatedIteratorState::Quoted(quote @ Quote::Single), '\'') => {
-                    if char_indices.peek().map(|(_, c)| *c) == Some('\\') {
+                CommaSeparatedIteratorState::Default => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Quoted(quote) if c == quote => {
+                    if char_indices.peek().map(|(_, c)| *c) == self.config.escape {
                         CommaSeparatedIteratorState::Quoted(quote)
                     } else {
                         CommaSeparatedIteratorState::Default
                     }
                 }
-                (CommaSeparatedIteratorState::Quoted(quote), _) => {
+                CommaSeparatedIteratorState::Quoted(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+                CommaSeparatedIteratorState::QuotedEscape(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+            };
+        }
+
+        let field = self.remaining;
+        self.remaining = "";
+        Some(decode(field, &self.config))
+    }
+}
+
+/// Strip quote marks and resolve escapes in a single field
+///
+/// Returns `Cow::Borrowed` when the field contains no quotes and needs no changes.
+fn decode<'a>(field: &'a str, config: &CommaSeparatedConfig) -> Cow<'a, str> {
+    if !field.contains(config.quote_chars.as_slice()) {
+        return Cow::Borrowed(field);
+    }
+
+    let mut result = String::with_capacity(field.len());
+    let mut state = CommaSeparatedIteratorState::Default;
+
+    for c in field.chars() {
+        state = match state {
+            CommaSeparatedIteratorState::Default if config.quote_chars.contains(&c) => {
+                CommaSeparatedIteratorState::Quoted(c)
+            }
+            CommaSeparatedIteratorState::Default => {
+                result.push(c);
+                CommaSeparatedIteratorState::Default
+            }
+            CommaSeparatedIteratorState::Quoted(quote) if c == quote => {
+                CommaSeparatedIteratorState::Default
+            }
+            CommaSeparatedIteratorState::Quoted(quote) if Some(c) == config.escape => {
+                CommaSeparatedIteratorState::QuotedEscape(quote)
+            }
+            CommaSeparatedIteratorState::Quoted(quote) => {
+                result.push(c);
+                CommaSeparatedIteratorState::Quoted(quote)
+            }
+            CommaSeparatedIteratorState::QuotedEscape(quote) => {
+                result.push(c);
+                CommaSeparatedIteratorState::Quoted(quote)
+            }
+        };
+    }
+
+    Cow::Owned(result)
+}
+
+/// An error produced while scanning malformed comma-separated input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommaError {
+    /// A quote was opened but never closed, `byte_offset` points at the opening quote
+    QuoteNotClosed { byte_offset: usize },
+    /// A quoted field ended with the escape character and no character left to escape, `byte_offset` points at the
+    /// escape character
+    DanglingEscape { byte_offset: usize },
+}
+
+impl fmt::Display for CommaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommaError::QuoteNotClosed { byte_offset } => {
+                write!(f, "quote opened at byte {byte_offset} was never closed")
+            }
+            CommaError::DanglingEscape { byte_offset } => {
+                write!(f, "dangling escape character at byte {byte_offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommaError {}
+
+/// Iterator over a comma-seperated string that reports unterminated quotes and dangling escapes instead of
+/// guessing where they end
+///
+/// Created by [`CommaSeparatedIterator::try_next`] or [`CommaSeparatedIteratorBuilder::try_build`].
+pub struct CommaSeparatedResult<'a> {
+    remaining: &'a str,
+    config: CommaSeparatedConfig,
+    offset: usize,
+}
+
+impl<'a> CommaSeparatedResult<'a> {
+    /// Create a new fallible iterator, splitting the input into comma-seperated parts and reporting
+    /// malformed quoting instead of guessing
+    pub fn new(text: &'a str) -> Self {
+        CommaSeparatedIteratorBuilder::default().try_build(text)
+    }
+
+    /// Advance the iterator, returning the next field or the error that makes it unparsable
+    pub fn try_next(&mut self) -> Option<Result<&'a str, CommaError>> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let mut state = CommaSeparatedIteratorState::Default;
+        let mut quote_start = 0;
+        let char_indices = self.remaining.char_indices();
+
+        for (i, c) in char_indices {
+            state = match state {
+                CommaSeparatedIteratorState::Default if self.config.quote_chars.contains(&c) => {
+                    quote_start = i;
+                    CommaSeparatedIteratorState::Quoted(c)
+                }
+                CommaSeparatedIteratorState::Default if c == self.config.delimiter => {
+                    let result = &self.remaining[0..i];
+                    let consumed = i + c.len_utf8();
+                    self.remaining = &self.remaining[consumed..];
+                    self.offset += consumed;
+                    return Some(Ok(result));
+                }
+                CommaSeparatedIteratorState::Default => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Quoted(quote) if c == quote => {
+                    CommaSeparatedIteratorState::Default
+                }
+                CommaSeparatedIteratorState::Quoted(quote) if Some(c) == self.config.escape => {
+                    CommaSeparatedIteratorState::QuotedEscape(quote)
+                }
+                CommaSeparatedIteratorState::Quoted(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+                CommaSeparatedIteratorState::QuotedEscape(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+            };
+        }
+
+        let error = match state {
+            CommaSeparatedIteratorState::Quoted(_) => Some(CommaError::QuoteNotClosed {
+                byte_offset: self.offset + quote_start,
+            }),
+            CommaSeparatedIteratorState::QuotedEscape(_) => Some(CommaError::DanglingEscape {
+                byte_offset: self.offset + self.remaining.len()
+                    - self.config.escape.map_or(1, char::len_utf8),
+            }),
+            CommaSeparatedIteratorState::Default => None,
+        };
+
+        let result = self.remaining;
+        self.offset += result.len();
+        self.remaining = "";
+
+        match error {
+            Some(err) => Some(Err(err)),
+            None => Some(Ok(result)),
+        }
+    }
+}
+
+impl<'a> Iterator for CommaSeparatedResult<'a> {
+    type Item = Result<&'a str, CommaError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.try_next()
+    }
+}
+
+/// Iterator over a comma-seperated string that yields the byte-range span of each field within the original
+/// input alongside its content
+///
+/// Created by [`CommaSeparatedIterator::spans`] or [`CommaSeparatedIteratorBuilder::build_spans`].
+pub struct CommaSeparatedSpans<'a> {
+    remaining: &'a str,
+    config: CommaSeparatedConfig,
+    /// Absolute offset of `remaining` within the original input, advanced as fields are taken from the front
+    start: usize,
+}
+
+impl<'a> Iterator for CommaSeparatedSpans<'a> {
+    type Item = (Range<usize>, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let mut state = CommaSeparatedIteratorState::Default;
+        let char_indices = self.remaining.char_indices();
+
+        for (i, c) in char_indices {
+            state = match state {
+                CommaSeparatedIteratorState::Default if self.config.quote_chars.contains(&c) => {
+                    CommaSeparatedIteratorState::Quoted(c)
+                }
+                CommaSeparatedIteratorState::Default if c == self.config.delimiter => {
+                    let result = &self.remaining[0..i];
+                    let span = self.start..self.start + i;
+                    let consumed = i + c.len_utf8();
+                    self.remaining = &self.remaining[consumed..];
+                    self.start += consumed;
+                    return Some((span, result));
+                }
+                CommaSeparatedIteratorState::Default => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Quoted(quote) if c == quote => {
+                    CommaSeparatedIteratorState::Default
+                }
+                CommaSeparatedIteratorState::Quoted(quote) if Some(c) == self.config.escape => {
+                    CommaSeparatedIteratorState::QuotedEscape(quote)
+                }
+                CommaSeparatedIteratorState::Quoted(quote) => {
                     CommaSeparatedIteratorState::Quoted(quote)
                 }
-                (CommaSeparatedIteratorState::QuotedEscape(quote), _) => {
+                CommaSeparatedIteratorState::QuotedEscape(quote) => {
                     CommaSeparatedIteratorState::Quoted(quote)
                 }
-                (CommaSeparatedIteratorState::Default, ',') => {
-                    let result = &self.remaining[i + 1..];
+            };
+        }
+
+        let result = self.remaining;
+        let span = self.start..self.start + result.len();
+        self.start += result.len();
+        self.remaining = "";
+        Some((span, result))
+    }
+}
+
+impl<'a> DoubleEndedIterator for CommaSeparatedSpans<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let mut state = CommaSeparatedIteratorState::Default;
+        let mut char_indices = self.remaining.char_indices().rev().peekable();
+
+        while let Some((i, c)) = char_indices.next() {
+            state = match state {
+                CommaSeparatedIteratorState::Default if self.config.quote_chars.contains(&c) => {
+                    CommaSeparatedIteratorState::Quoted(c)
+                }
+                CommaSeparatedIteratorState::Default if c == self.config.delimiter => {
+                    let result = &self.remaining[i + c.len_utf8()..];
+                    let span = self.start + i + c.len_utf8()..self.start + self.remaining.len();
                     self.remaining = &self.remaining[0..i];
+                    return Some((span, result));
+                }
+                CommaSeparatedIteratorState::Default => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Quoted(quote) if c == quote => {
+                    if char_indices.peek().map(|(_, c)| *c) == self.config.escape {
+                        CommaSeparatedIteratorState::Quoted(quote)
+                    } else {
+                        CommaSeparatedIteratorState::Default
+                    }
+                }
+                CommaSeparatedIteratorState::Quoted(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+                CommaSeparatedIteratorState::QuotedEscape(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+            };
+        }
+
+        let span = self.start..self.start + self.remaining.len();
+        let result = self.remaining;
+        self.remaining = "";
+        Some((span, result))
+    }
+}
+
+/// Iterator over a comma-seperated string that keeps the trailing delimiter as part of each yielded field
+///
+/// Concatenating every yielded field reproduces the original input exactly, which makes this useful for
+/// editing workflows that rewrite individual fields and re-emit the rest verbatim.
+///
+/// Created by [`CommaSeparatedIterator::split_inclusive`] or [`CommaSeparatedIteratorBuilder::build_inclusive`].
+pub struct CommaSeparatedInclusive<'a> {
+    remaining: &'a str,
+    config: CommaSeparatedConfig,
+}
+
+impl<'a> Iterator for CommaSeparatedInclusive<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let mut state = CommaSeparatedIteratorState::Default;
+        let char_indices = self.remaining.char_indices();
+
+        for (i, c) in char_indices {
+            state = match state {
+                CommaSeparatedIteratorState::Default if self.config.quote_chars.contains(&c) => {
+                    CommaSeparatedIteratorState::Quoted(c)
+                }
+                CommaSeparatedIteratorState::Default if c == self.config.delimiter => {
+                    let consumed = i + c.len_utf8();
+                    let result = &self.remaining[0..consumed];
+                    self.remaining = &self.remaining[consumed..];
                     return Some(result);
                 }
-                (CommaSeparatedIteratorState::Default, _) => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Default => CommaSeparatedIteratorState::Default,
+                CommaSeparatedIteratorState::Quoted(quote) if c == quote => {
+                    CommaSeparatedIteratorState::Default
+                }
+                CommaSeparatedIteratorState::Quoted(quote) if Some(c) == self.config.escape => {
+                    CommaSeparatedIteratorState::QuotedEscape(quote)
+                }
+                CommaSeparatedIteratorState::Quoted(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
+                CommaSeparatedIteratorState::QuotedEscape(quote) => {
+                    CommaSeparatedIteratorState::Quoted(quote)
+                }
             };
         }
 
@@ -132,7 +664,8 @@ impl<'a> DoubleEndedIterator for CommaSeparatedIterator<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::CommaSeparatedIterator;
+    use crate::{CommaError, CommaSeparatedIterator};
+    use std::borrow::Cow;
 
     #[test]
     fn test_comma_separated_iterator() {
@@ -191,4 +724,172 @@ mod tests {
         assert_eq!(Some("b"), iter.next());
         assert_eq!(Some("c"), iter.next_back());
     }
+
+    #[test]
+    fn test_comma_separated_values() {
+        assert_eq!(
+            vec!["abc,def", "ghi", "jkl", "mno", "pqr", "abc, def", "foo", "foo", ",foo", "fo'o"],
+            CommaSeparatedIterator::new(
+                r#""abc,def", "ghi","jkl" , "mno",pqr, "abc, def", foo, " foo", ',foo', "fo'o""#
+            )
+            .values()
+            .map(|value| value.trim().to_string())
+            .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec!["foobarbaz", "a\"b"],
+            CommaSeparatedIterator::new("foo\"bar\"baz,\"a\\\"b\"")
+                .values()
+                .collect::<Vec<_>>()
+        );
+
+        let mut iter = CommaSeparatedIterator::new(r#"abc,"def""#).values();
+        assert!(matches!(iter.next(), Some(Cow::Borrowed("abc"))));
+        assert!(matches!(iter.next(), Some(Cow::Owned(value)) if value == "def"));
+    }
+
+    #[test]
+    fn test_comma_separated_iterator_builder() {
+        assert_eq!(
+            vec!["foo", "'bar;baz'", "qux"],
+            CommaSeparatedIterator::builder()
+                .delimiter(';')
+                .build("foo;'bar;baz';qux")
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec!["foo", "bar\\", "baz"],
+            CommaSeparatedIterator::builder()
+                .no_escape()
+                .build(r#""foo","bar\","baz""#)
+                .values()
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec!["'foo'", "bar"],
+            CommaSeparatedIterator::builder()
+                .quote_chars(&['"'])
+                .build("'foo',\"bar\"")
+                .values()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_comma_separated_iterator_builder_multi_byte_delimiter() {
+        assert_eq!(
+            vec!["a", "b", "c"],
+            CommaSeparatedIterator::builder()
+                .delimiter('€')
+                .build("a€b€c")
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["c", "b", "a"],
+            CommaSeparatedIterator::builder()
+                .delimiter('€')
+                .build("a€b€c")
+                .rev()
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            Ok(vec!["a", "b", "c"]),
+            CommaSeparatedIterator::builder()
+                .delimiter('€')
+                .build("a€b€c")
+                .try_next()
+                .collect::<Result<Vec<_>, _>>()
+        );
+
+        assert_eq!(
+            vec![(0..1, "a"), (4..5, "b"), (8..9, "c")],
+            CommaSeparatedIterator::builder()
+                .delimiter('€')
+                .build_spans("a€b€c")
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(8..9, "c"), (4..5, "b"), (0..1, "a")],
+            CommaSeparatedIterator::builder()
+                .delimiter('€')
+                .build_spans("a€b€c")
+                .rev()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_comma_separated_try_next() {
+        assert_eq!(
+            Ok(vec!["foo", "\"bar\"", "baz"]),
+            CommaSeparatedIterator::new(r#"foo,"bar",baz"#)
+                .try_next()
+                .collect::<Result<Vec<_>, _>>()
+        );
+
+        assert_eq!(
+            Some(Err(CommaError::QuoteNotClosed { byte_offset: 4 })),
+            CommaSeparatedIterator::new(r#"foo,"bar"#)
+                .try_next()
+                .collect::<Vec<_>>()
+                .pop()
+        );
+
+        assert_eq!(
+            Some(Err(CommaError::DanglingEscape { byte_offset: 4 })),
+            CommaSeparatedIterator::new(r#""bar\"#)
+                .try_next()
+                .collect::<Vec<_>>()
+                .pop()
+        );
+    }
+
+    #[test]
+    fn test_comma_separated_spans() {
+        let input = r#"foo,"bar, baz",qux"#;
+        assert_eq!(
+            vec![(0..3, "foo"), (4..14, "\"bar, baz\""), (15..18, "qux"),],
+            CommaSeparatedIterator::spans(input).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(15..18, "qux"), (4..14, "\"bar, baz\""), (0..3, "foo"),],
+            CommaSeparatedIterator::spans(input)
+                .rev()
+                .collect::<Vec<_>>()
+        );
+
+        for (span, field) in CommaSeparatedIterator::spans(input) {
+            assert_eq!(&input[span], field);
+        }
+
+        let mut spans = CommaSeparatedIterator::spans(input);
+        assert_eq!(Some((0..3, "foo")), spans.next());
+        assert_eq!(Some((15..18, "qux")), spans.next_back());
+        assert_eq!(Some((4..14, "\"bar, baz\"")), spans.next());
+    }
+
+    #[test]
+    fn test_comma_separated_split_inclusive() {
+        let input = r#"foo,"bar, baz",qux"#;
+        let fields = CommaSeparatedIterator::split_inclusive(input).collect::<Vec<_>>();
+        assert_eq!(vec!["foo,", "\"bar, baz\",", "qux"], fields);
+        assert_eq!(input, fields.concat());
+
+        let input = "foo,bar,";
+        let fields = CommaSeparatedIterator::split_inclusive(input).collect::<Vec<_>>();
+        assert_eq!(vec!["foo,", "bar,"], fields);
+        assert_eq!(input, fields.concat());
+
+        let input = "a€b€c";
+        let fields = CommaSeparatedIterator::builder()
+            .delimiter('€')
+            .build_inclusive(input)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["a€", "b€", "c"], fields);
+        assert_eq!(input, fields.concat());
+    }
 }